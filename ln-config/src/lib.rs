@@ -0,0 +1,10 @@
+pub mod summarization;
+pub mod theme;
+
+pub use summarization::SummarizationConfig;
+pub use theme::ThemeConfig;
+
+// `Config` (defined elsewhere in this crate) gains `theme: ThemeConfig` and
+// `summarization: SummarizationConfig` fields; both types live in this crate,
+// not `ln-main`, to keep the dependency acyclic (`ln-main` depends on
+// `ln-config`, not the other way around).
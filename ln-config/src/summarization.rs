@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+/// The `[summarization]` section of `Config`: whether AI summarization of long
+/// post bodies is enabled, and how to reach the completion endpoint that
+/// produces them.
+///
+/// Lives here rather than in `ln-main` for the same reason as `ThemeConfig`:
+/// `Config` owns a `summarization` field of this type, and defining it in
+/// `ln-main` would make `ln-config` depend back on `ln-main`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SummarizationConfig {
+    pub enabled: bool,
+    pub model_name: String,
+    pub base_url: String,
+    pub api_key: String,
+    pub capacity: usize,
+    pub token_threshold: usize,
+}
@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+/// The `[theme]` section of `Config`: a named built-in preset plus optional
+/// per-field overrides, and an optional file to hot-reload from on
+/// `Action::ReloadTheme`.
+///
+/// Lives here rather than in `ln-main` because `Config` owns a `theme` field of
+/// this type, and `ln-main` already depends on `ln-config` -- defining it
+/// there would make the two crates depend on each other.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ThemeConfig {
+    pub preset: Option<String>,
+    pub path: Option<PathBuf>,
+    pub focused_border: Option<String>,
+    pub selected_highlight: Option<String>,
+    pub link: Option<String>,
+    pub bottom_bar: Option<String>,
+    pub unread_badge: Option<String>,
+}
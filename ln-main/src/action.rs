@@ -0,0 +1,152 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+use crate::{status::RequestStatus, ui::inbox::Conversation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Normal,
+    Input,
+    Command,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Quit,
+    Render,
+    ScrollUp(usize),
+    ScrollDown(usize),
+    JumpToFirst,
+    JumpToLast,
+    PageUp,
+    PageDown,
+    HalfPageUp,
+    HalfPageDown,
+    FetchNextPage,
+    RequestStarted { id: u64, label: String },
+    RequestFinished { id: u64, status: RequestStatus },
+    SummarizeFocused,
+    PostSummarized { post_id: i32, summary: String },
+    PostSummaryFailed { post_id: i32, error: String },
+    ToggleInbox,
+    InboxComposeReply,
+    InboxUpdated(Vec<Conversation>),
+    SwitchToInputMode,
+    SwitchToNormalMode,
+    SwitchToCommandMode,
+    InputChar(char),
+    InputBackspace,
+    InputSubmit,
+    CommandPaletteInput(char),
+    CommandPaletteBackspace,
+    CommandPaletteSelectNext,
+    CommandPaletteSelectPrev,
+    CommandPaletteSubmit,
+    ReloadTheme,
+}
+
+impl Action {
+    pub fn is_render(&self) -> bool {
+        matches!(self, Action::Render)
+    }
+}
+
+pub fn event_to_action(mode: Mode, event: Event) -> Option<Action> {
+    let Event::Key(KeyEvent { code, .. }) = event else {
+        return None;
+    };
+
+    match mode {
+        Mode::Normal => match code {
+            KeyCode::Char('q') => Some(Action::Quit),
+            KeyCode::Char('j') | KeyCode::Down => Some(Action::ScrollDown(1)),
+            KeyCode::Char('k') | KeyCode::Up => Some(Action::ScrollUp(1)),
+            KeyCode::Char('i') => Some(Action::SwitchToInputMode),
+            KeyCode::Char(':') => Some(Action::SwitchToCommandMode),
+            _ => None,
+        },
+
+        Mode::Input => match code {
+            KeyCode::Esc => Some(Action::SwitchToNormalMode),
+            KeyCode::Enter => Some(Action::InputSubmit),
+            KeyCode::Backspace => Some(Action::InputBackspace),
+            KeyCode::Char(c) => Some(Action::InputChar(c)),
+            _ => None,
+        },
+
+        Mode::Command => match code {
+            KeyCode::Esc => Some(Action::SwitchToNormalMode),
+            KeyCode::Enter => Some(Action::CommandPaletteSubmit),
+            KeyCode::Backspace => Some(Action::CommandPaletteBackspace),
+            KeyCode::Down => Some(Action::CommandPaletteSelectNext),
+            KeyCode::Up => Some(Action::CommandPaletteSelectPrev),
+            KeyCode::Char(c) => Some(Action::CommandPaletteInput(c)),
+            _ => None,
+        },
+    }
+}
+
+/// Accumulates a pending numeric count and a pending `g` (for the `gg` motion)
+/// across keystrokes in `Mode::Normal`, then resolves them into a single `Action`.
+/// Other modes pass straight through to `event_to_action`.
+#[derive(Default)]
+pub struct KeyInterpreter {
+    pending_count: Option<u32>,
+    pending_g: bool,
+}
+
+impl KeyInterpreter {
+    pub fn interpret(&mut self, mode: Mode, event: Event) -> Option<Action> {
+        if mode != Mode::Normal {
+            self.pending_count = None;
+            self.pending_g = false;
+            return event_to_action(mode, event);
+        }
+
+        let Event::Key(KeyEvent {
+            code, modifiers, ..
+        }) = event
+        else {
+            return None;
+        };
+
+        if let KeyCode::Char(digit @ '0'..='9') = code {
+            if digit != '0' || self.pending_count.is_some() {
+                let digit = digit.to_digit(10).unwrap();
+                self.pending_count = Some(self.pending_count.unwrap_or(0) * 10 + digit);
+                return None;
+            }
+        }
+
+        let count = self.pending_count.take().unwrap_or(1) as usize;
+
+        if self.pending_g {
+            self.pending_g = false;
+            return match code {
+                KeyCode::Char('g') => Some(Action::JumpToFirst),
+                _ => None,
+            };
+        }
+
+        match code {
+            KeyCode::Char('g') => {
+                self.pending_g = true;
+                None
+            }
+            KeyCode::Char('G') => Some(Action::JumpToLast),
+            KeyCode::Char('{') => Some(Action::PageUp),
+            KeyCode::Char('}') => Some(Action::PageDown),
+            KeyCode::Char('d') if modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::HalfPageDown)
+            }
+            KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Action::HalfPageUp)
+            }
+            KeyCode::Char('j') | KeyCode::Down => Some(Action::ScrollDown(count)),
+            KeyCode::Char('k') | KeyCode::Up => Some(Action::ScrollUp(count)),
+            KeyCode::Char('s') => Some(Action::SummarizeFocused),
+            KeyCode::Char('m') => Some(Action::ToggleInbox),
+            KeyCode::Char('r') => Some(Action::InboxComposeReply),
+            _ => event_to_action(mode, Event::Key(KeyEvent::new(code, modifiers))),
+        }
+    }
+}
@@ -1,23 +1,26 @@
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
 
 use lemmy_api_common::{
     person::{Login, LoginResponse},
     sensitive::Sensitive,
 };
 use ln_config::Config;
-use ratatui_image::picker::Picker;
-use reqwest::{
-    header::{HeaderMap, HeaderValue},
-    Client,
+use ratatui::{
+    prelude::*,
+    widgets::Paragraph,
 };
+use ratatui_image::picker::Picker;
+use reqwest::Client;
 
 use crate::{
-    action::{event_to_action, Action, Mode},
+    action::{Action, KeyInterpreter, Mode},
+    status::{next_request_id, RequestStatus, StatusTracker},
+    theme::Theme,
     tui::Tui,
     ui::{components::Component, main_ui::MainWindow},
 };
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 
 pub struct App {
@@ -26,15 +29,33 @@ pub struct App {
     action_rx: UnboundedReceiver<Action>,
     main_window: MainWindow,
     mode: Mode,
+    key_interpreter: KeyInterpreter,
+    status_tracker: StatusTracker,
+    ctx: Arc<Ctx>,
 }
 
 pub struct Ctx {
     pub action_tx: UnboundedSender<Action>,
+    /// Carries no default `Authorization` header: login now happens in the
+    /// background (see `spawn_login`), so the token isn't available yet when
+    /// this `Client` is built. Authenticated requests must attach `Ctx::jwt()`
+    /// themselves -- the same `auth` field Lemmy's own request types already
+    /// require (see `ui::inbox`).
     pub client: Client,
+    pub jwt: Mutex<Option<String>>,
     pub picker: Mutex<Picker>,
+    pub theme: RwLock<Theme>,
     pub config: Config,
 }
 
+impl Ctx {
+    /// The logged-in JWT, once `spawn_login` has finished. `None` before login
+    /// completes or if it failed.
+    pub fn jwt(&self) -> Option<String> {
+        self.jwt.lock().unwrap().clone()
+    }
+}
+
 impl App {
     pub async fn new(config: Config) -> Result<Self> {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
@@ -42,49 +63,31 @@ impl App {
 
         let client = Client::builder().user_agent(user_agent).build()?;
 
-        let login_req = Login {
-            username_or_email: Sensitive::new(config.connection.username.clone()),
-            password: Sensitive::new(config.connection.password.clone()),
-            ..Default::default()
-        };
-
-        let res: LoginResponse = client
-            .post(format!(
-                "https://{}/api/v3/user/login",
-                config.connection.instance
-            ))
-            .json(&login_req)
-            .send()
-            .await?
-            .json()
-            .await?;
-
-        let mut header_map = HeaderMap::new();
-        header_map.insert(
-            reqwest::header::AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", &res.jwt.as_ref().unwrap()[..]))?,
-        );
-        let client = Client::builder()
-            .user_agent(user_agent)
-            .default_headers(header_map)
-            .build()?;
-
         let mut picker = Picker::from_termios().unwrap();
         picker.guess_protocol();
 
+        let theme = Theme::from_config(&config.theme);
+
         let ctx = Arc::new(Ctx {
             action_tx: action_tx.clone(),
             client,
+            jwt: Mutex::new(None),
             picker: Mutex::new(picker),
+            theme: RwLock::new(theme),
             config,
         });
 
+        spawn_login(Arc::clone(&ctx));
+
         Ok(Self {
             should_quit: false,
             main_window: MainWindow::new(Arc::clone(&ctx)).await?,
             action_tx,
             action_rx,
             mode: Mode::Normal,
+            key_interpreter: KeyInterpreter::default(),
+            status_tracker: StatusTracker::default(),
+            ctx,
         })
     }
 
@@ -107,7 +110,7 @@ impl App {
 
             tokio::select! {
                 event = tui_event => {
-                    if let Some(action) = event_to_action(self.mode, event.unwrap()) {
+                    if let Some(action) = self.key_interpreter.interpret(self.mode, event.unwrap()) {
                         if let Some(action) = self.update(action) {
                             self.action_tx.send(action).unwrap();
                         }
@@ -132,8 +135,23 @@ impl App {
     }
 
     fn render(&mut self, tui: &mut Tui) -> Result<()> {
+        self.status_tracker.prune_expired();
+
         tui.terminal.draw(|f| {
-            self.main_window.render(f, f.size());
+            let toast_lines = self.status_tracker.toast_lines();
+            if toast_lines.is_empty() {
+                self.main_window.render(f, f.size());
+                return;
+            }
+
+            let layout = Layout::vertical([
+                Constraint::Min(0),
+                Constraint::Length(toast_lines.len() as u16),
+            ])
+            .split(f.size());
+
+            self.main_window.render(f, layout[0]);
+            f.render_widget(Paragraph::new(toast_lines.join("\n")), layout[1]);
         })?;
         Ok(())
     }
@@ -149,6 +167,21 @@ impl App {
 
             A::Render => Some(A::Render),
 
+            A::RequestStarted { id, label } => {
+                self.status_tracker.start(*id, label.clone());
+                Some(A::Render)
+            }
+
+            A::RequestFinished { id, status } => {
+                self.status_tracker.finish(*id, status.clone());
+                Some(A::Render)
+            }
+
+            A::ReloadTheme => {
+                reload_theme(&self.ctx);
+                Some(A::Render)
+            }
+
             A::SwitchToInputMode => {
                 self.mode = Mode::Input;
                 Some(A::Render)
@@ -156,10 +189,83 @@ impl App {
 
             A::SwitchToNormalMode => {
                 self.mode = Mode::Normal;
+                self.main_window.handle_actions(action);
                 Some(A::Render)
             }
 
+            A::SwitchToCommandMode => {
+                self.mode = Mode::Command;
+                self.main_window.handle_actions(action)
+            }
+
             _ => self.main_window.handle_actions(action),
         }
     }
 }
+
+/// Re-resolves `ctx.theme` from `ctx.config.theme`, re-reading the theme's `path`
+/// file (if set) so users can tune contrast for their terminal without
+/// recompiling. Falls back to the in-config theme if the file is missing or
+/// fails to parse.
+fn reload_theme(ctx: &Ctx) {
+    let mut theme_config = ctx.config.theme.clone();
+
+    if let Some(path) = &theme_config.path {
+        if let Ok(contents) = std::fs::read_to_string(path) {
+            if let Ok(file_config) = toml::from_str(&contents) {
+                theme_config = file_config;
+            }
+        }
+    }
+
+    *ctx.theme.write().unwrap() = Theme::from_config(&theme_config);
+}
+
+/// Logs in against the configured instance in the background, storing the JWT in
+/// `ctx.jwt` on success, so a bad instance, wrong password, or network hiccup
+/// surfaces as a recoverable toast instead of a fatal startup error.
+fn spawn_login(ctx: Arc<Ctx>) {
+    let id = next_request_id();
+    let _ = ctx.action_tx.send(Action::RequestStarted {
+        id,
+        label: "login".to_string(),
+    });
+
+    tokio::spawn(async move {
+        let status = match login(&ctx).await {
+            Ok(jwt) => {
+                *ctx.jwt.lock().unwrap() = Some(jwt);
+                RequestStatus::Done
+            }
+            Err(err) => RequestStatus::Error(err.to_string()),
+        };
+
+        let _ = ctx.action_tx.send(Action::RequestFinished { id, status });
+        let _ = ctx.action_tx.send(Action::Render);
+    });
+}
+
+async fn login(ctx: &Ctx) -> Result<String> {
+    let login_req = Login {
+        username_or_email: Sensitive::new(ctx.config.connection.username.clone()),
+        password: Sensitive::new(ctx.config.connection.password.clone()),
+        ..Default::default()
+    };
+
+    let res: LoginResponse = ctx
+        .client
+        .post(format!(
+            "https://{}/api/v3/user/login",
+            ctx.config.connection.instance
+        ))
+        .json(&login_req)
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    res.jwt
+        .as_ref()
+        .map(|jwt| jwt[..].to_string())
+        .context("login response did not include a jwt")
+}
@@ -0,0 +1,104 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tiktoken_rs::CoreBPE;
+
+/// Which end of the content a `truncate` call keeps when it has to cut tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TruncationDirection {
+    Start,
+    End,
+}
+
+/// A backend capable of summarizing post bodies. `count_tokens`/`truncate` use the
+/// model's own tokenizer so truncation always lands on a token boundary instead of
+/// cutting mid-token.
+#[async_trait]
+pub trait LanguageModel: Send + Sync {
+    fn name(&self) -> &str;
+    fn count_tokens(&self, content: &str) -> usize;
+    fn truncate(&self, content: &str, max_tokens: usize, direction: TruncationDirection) -> String;
+    fn capacity(&self) -> usize;
+    async fn summarize(&self, content: &str) -> Result<String>;
+}
+
+/// `LanguageModel` backed by a configurable HTTP completion endpoint, using a BPE
+/// tokenizer for token accounting.
+pub struct HttpLanguageModel {
+    name: String,
+    base_url: String,
+    api_key: String,
+    capacity: usize,
+    client: reqwest::Client,
+    tokenizer: CoreBPE,
+}
+
+impl HttpLanguageModel {
+    pub fn new(name: String, base_url: String, api_key: String, capacity: usize) -> Result<Self> {
+        Ok(Self {
+            name,
+            base_url,
+            api_key,
+            capacity,
+            client: reqwest::Client::new(),
+            tokenizer: tiktoken_rs::cl100k_base().context("failed to load BPE tokenizer")?,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct CompletionRequest<'a> {
+    model: &'a str,
+    prompt: &'a str,
+}
+
+#[derive(Deserialize)]
+struct CompletionResponse {
+    completion: String,
+}
+
+#[async_trait]
+impl LanguageModel for HttpLanguageModel {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn count_tokens(&self, content: &str) -> usize {
+        self.tokenizer.encode_ordinary(content).len()
+    }
+
+    fn truncate(&self, content: &str, max_tokens: usize, direction: TruncationDirection) -> String {
+        let tokens = self.tokenizer.encode_ordinary(content);
+        if tokens.len() <= max_tokens {
+            return content.to_string();
+        }
+
+        let kept = match direction {
+            TruncationDirection::End => &tokens[tokens.len() - max_tokens..],
+            TruncationDirection::Start => &tokens[..max_tokens],
+        };
+
+        self.tokenizer.decode(kept.to_vec()).unwrap_or_default()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    async fn summarize(&self, content: &str) -> Result<String> {
+        let response: CompletionResponse = self
+            .client
+            .post(format!("{}/v1/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&CompletionRequest {
+                model: &self.name,
+                prompt: content,
+            })
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        Ok(response.completion)
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+pub fn next_request_id() -> u64 {
+    NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// How long a failed request's toast stays visible before `prune_expired` drops
+/// it, so a repeatedly failing background poll can't grow the toast area
+/// without bound.
+const ERROR_TTL: Duration = Duration::from_secs(8);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestStatus {
+    Pending,
+    Done,
+    Error(String),
+}
+
+struct TrackedRequest {
+    id: u64,
+    label: String,
+    status: RequestStatus,
+    /// When this request's status last became `Error`; `None` while `Pending`.
+    error_since: Option<Instant>,
+}
+
+/// Tracks in-flight async operations (login, inbox polling, sending a message,
+/// summarizing a post) by id so the UI can render a transient toast per request
+/// instead of the app crashing or hanging on a bad instance, expired token, or
+/// rate-limit.
+#[derive(Default)]
+pub struct StatusTracker {
+    requests: Vec<TrackedRequest>,
+}
+
+impl StatusTracker {
+    pub fn start(&mut self, id: u64, label: impl Into<String>) {
+        self.requests.push(TrackedRequest {
+            id,
+            label: label.into(),
+            status: RequestStatus::Pending,
+            error_since: None,
+        });
+    }
+
+    pub fn finish(&mut self, id: u64, status: RequestStatus) {
+        if status == RequestStatus::Done {
+            self.requests.retain(|request| request.id != id);
+            return;
+        }
+
+        if let Some(request) = self.requests.iter_mut().find(|request| request.id == id) {
+            request.error_since = Some(Instant::now());
+            request.status = status;
+        }
+    }
+
+    /// Drops errors that have been visible for longer than `ERROR_TTL`. Call
+    /// before rendering so a toast never lingers past the "transient" the
+    /// feature promises.
+    pub fn prune_expired(&mut self) {
+        self.requests.retain(|request| match request.error_since {
+            Some(since) => since.elapsed() < ERROR_TTL,
+            None => true,
+        });
+    }
+
+    /// One line per in-flight or failed request: a spinner for pending work, the
+    /// trimmed error text for failures. Empty once everything has finished cleanly.
+    pub fn toast_lines(&self) -> Vec<String> {
+        self.requests
+            .iter()
+            .map(|request| match &request.status {
+                RequestStatus::Pending => format!("⠋ {}...", request.label),
+                RequestStatus::Error(error) => format!("✗ {}: {}", request.label, trim_error(error)),
+                RequestStatus::Done => unreachable!("done requests are removed on finish"),
+            })
+            .collect()
+    }
+}
+
+fn trim_error(error: &str) -> String {
+    const MAX_LEN: usize = 120;
+
+    match error.char_indices().nth(MAX_LEN) {
+        Some((byte_index, _)) => format!("{}...", &error[..byte_index]),
+        None => error.to_string(),
+    }
+}
@@ -0,0 +1,87 @@
+use ln_config::ThemeConfig;
+use ratatui::style::{Color, Style};
+
+/// Resolved colors every `Component::render` pulls `Style`s from, instead of
+/// hardcoding colors at each render site.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub focused_border: Color,
+    pub selected_highlight: Color,
+    pub link: Color,
+    pub bottom_bar: Color,
+    pub unread_badge: Color,
+}
+
+impl Theme {
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        let mut theme = preset(config.preset.as_deref().unwrap_or("default"));
+
+        if let Some(color) = config.focused_border.as_deref().and_then(parse_color) {
+            theme.focused_border = color;
+        }
+        if let Some(color) = config.selected_highlight.as_deref().and_then(parse_color) {
+            theme.selected_highlight = color;
+        }
+        if let Some(color) = config.link.as_deref().and_then(parse_color) {
+            theme.link = color;
+        }
+        if let Some(color) = config.bottom_bar.as_deref().and_then(parse_color) {
+            theme.bottom_bar = color;
+        }
+        if let Some(color) = config.unread_badge.as_deref().and_then(parse_color) {
+            theme.unread_badge = color;
+        }
+
+        theme
+    }
+
+    pub fn focused_border_style(&self) -> Style {
+        Style::default().fg(self.focused_border)
+    }
+
+    pub fn selected_highlight_style(&self) -> Style {
+        Style::default().bg(self.selected_highlight)
+    }
+
+    pub fn link_style(&self) -> Style {
+        Style::default().fg(self.link)
+    }
+
+    pub fn bottom_bar_style(&self) -> Style {
+        Style::default().fg(self.bottom_bar)
+    }
+
+    pub fn unread_badge_style(&self) -> Style {
+        Style::default().fg(self.unread_badge)
+    }
+}
+
+fn preset(name: &str) -> Theme {
+    match name {
+        "dracula" => Theme {
+            focused_border: Color::Rgb(189, 147, 249),
+            selected_highlight: Color::Rgb(68, 71, 90),
+            link: Color::Rgb(139, 233, 253),
+            bottom_bar: Color::Rgb(98, 114, 164),
+            unread_badge: Color::Rgb(255, 121, 198),
+        },
+        "gruvbox" => Theme {
+            focused_border: Color::Rgb(250, 189, 47),
+            selected_highlight: Color::Rgb(60, 56, 54),
+            link: Color::Rgb(131, 165, 152),
+            bottom_bar: Color::Rgb(146, 131, 116),
+            unread_badge: Color::Rgb(251, 73, 52),
+        },
+        _ => Theme {
+            focused_border: Color::Cyan,
+            selected_highlight: Color::DarkGray,
+            link: Color::Blue,
+            bottom_bar: Color::Gray,
+            unread_badge: Color::Red,
+        },
+    }
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    value.parse().ok()
+}
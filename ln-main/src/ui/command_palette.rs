@@ -0,0 +1,194 @@
+use std::sync::Arc;
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+
+use crate::{action::Action, app::Ctx, ui::components::Component};
+
+struct RegisteredAction {
+    name: &'static str,
+    action: Action,
+}
+
+const REGISTERED_ACTIONS: &[RegisteredAction] = &[
+    RegisteredAction {
+        name: "quit",
+        action: Action::Quit,
+    },
+    RegisteredAction {
+        name: "scroll down",
+        action: Action::ScrollDown(1),
+    },
+    RegisteredAction {
+        name: "scroll up",
+        action: Action::ScrollUp(1),
+    },
+    RegisteredAction {
+        name: "enter input mode",
+        action: Action::SwitchToInputMode,
+    },
+    RegisteredAction {
+        name: "toggle inbox",
+        action: Action::ToggleInbox,
+    },
+    RegisteredAction {
+        name: "reload theme",
+        action: Action::ReloadTheme,
+    },
+];
+
+/// Overlay opened with `:` that fuzzy-filters `REGISTERED_ACTIONS`. `MainWindow`
+/// reads the selected action off `execute_selected` on Enter and dispatches it
+/// itself, after closing the palette.
+pub struct CommandPalette {
+    ctx: Arc<Ctx>,
+    query: String,
+    matches: Vec<usize>,
+    selected: usize,
+    recency: Vec<usize>,
+}
+
+impl CommandPalette {
+    /// `recency` is carried in from the previous palette session (recency doesn't
+    /// reset just because the palette closed and reopened).
+    pub fn new(ctx: Arc<Ctx>, recency: Vec<usize>) -> Self {
+        let mut palette = Self {
+            ctx,
+            query: String::new(),
+            matches: vec![],
+            selected: 0,
+            recency,
+        };
+        palette.recompute_matches();
+        palette
+    }
+
+    /// Hands the accumulated recency ordering back to the caller so it can be
+    /// threaded into the next palette session.
+    pub fn into_recency(self) -> Vec<usize> {
+        self.recency
+    }
+
+    fn recompute_matches(&mut self) {
+        let mut scored: Vec<(usize, i32)> = REGISTERED_ACTIONS
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| fuzzy_score(&self.query, entry.name).map(|score| (i, score)))
+            .collect();
+
+        scored.sort_by(|(index_a, score_a), (index_b, score_b)| {
+            score_b.cmp(score_a).then_with(|| {
+                let recency_a = self.recency.iter().position(|i| i == index_a).unwrap_or(usize::MAX);
+                let recency_b = self.recency.iter().position(|i| i == index_b).unwrap_or(usize::MAX);
+                recency_a.cmp(&recency_b)
+            })
+        });
+
+        self.matches = scored.into_iter().map(|(i, _)| i).collect();
+        self.selected = 0;
+    }
+
+    /// Returns the chosen `Action` for the caller to dispatch once the palette
+    /// itself has been closed, rather than sending it through `action_tx` while
+    /// the palette is still open (where it would just be routed back into the
+    /// palette's own `handle_actions` and dropped).
+    pub(crate) fn execute_selected(&mut self) -> Option<Action> {
+        let index = *self.matches.get(self.selected)?;
+
+        self.recency.retain(|i| *i != index);
+        self.recency.insert(0, index);
+
+        Some(REGISTERED_ACTIONS[index].action.clone())
+    }
+}
+
+/// Scores `candidate` as a fuzzy subsequence match of `query`, rewarding consecutive
+/// character runs. Returns `None` when `query` isn't a subsequence of `candidate`.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate_lower = candidate.to_lowercase();
+    let mut candidate_chars = candidate_lower.chars();
+    let mut score = 0;
+    let mut consecutive = 0;
+
+    for needle in query.to_lowercase().chars() {
+        loop {
+            match candidate_chars.next() {
+                Some(c) if c == needle => {
+                    consecutive += 1;
+                    score += consecutive;
+                    break;
+                }
+                Some(_) => consecutive = 0,
+                None => return None,
+            }
+        }
+    }
+
+    Some(score)
+}
+
+impl Component for CommandPalette {
+    fn handle_actions(&mut self, action: Action) -> Option<Action> {
+        match action {
+            Action::CommandPaletteInput(c) => {
+                self.query.push(c);
+                self.recompute_matches();
+                Some(Action::Render)
+            }
+
+            Action::CommandPaletteBackspace => {
+                self.query.pop();
+                self.recompute_matches();
+                Some(Action::Render)
+            }
+
+            Action::CommandPaletteSelectNext => {
+                if !self.matches.is_empty() {
+                    self.selected = (self.selected + 1).min(self.matches.len() - 1);
+                }
+                Some(Action::Render)
+            }
+
+            Action::CommandPaletteSelectPrev => {
+                self.selected = self.selected.saturating_sub(1);
+                Some(Action::Render)
+            }
+
+            // `CommandPaletteSubmit` is handled by `MainWindow`, which closes the
+            // palette before dispatching the chosen action — see `execute_selected`.
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let layout = Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).split(rect);
+
+        let input = Paragraph::new(format!(": {}", self.query))
+            .block(Block::default().borders(Borders::ALL).title("Command"));
+        f.render_widget(input, layout[0]);
+
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .map(|&i| ListItem::new(REGISTERED_ACTIONS[i].name))
+            .collect();
+
+        let mut state = ListState::default();
+        if !self.matches.is_empty() {
+            state.select(Some(self.selected));
+        }
+
+        let theme = self.ctx.theme.read().unwrap();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL))
+            .highlight_style(theme.selected_highlight_style());
+
+        f.render_stateful_widget(list, layout[1], &mut state);
+    }
+}
@@ -0,0 +1,8 @@
+use ratatui::prelude::*;
+
+use crate::action::Action;
+
+pub trait Component {
+    fn handle_actions(&mut self, action: Action) -> Option<Action>;
+    fn render(&mut self, f: &mut Frame, rect: Rect);
+}
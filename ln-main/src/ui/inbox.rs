@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use lemmy_api_common::{
+    lemmy_db_views::structs::PrivateMessageView,
+    person::{CreatePrivateMessage, GetPrivateMessages, PrivateMessagesResponse},
+};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use tokio::time::{interval, Duration};
+
+use crate::{
+    action::Action,
+    app::Ctx,
+    status::{next_request_id, RequestStatus},
+    ui::components::Component,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversation {
+    pub other_party_id: i32,
+    pub other_party_name: String,
+    pub latest_message: String,
+    pub read: bool,
+}
+
+/// Polls the private-message inbox in the background and renders it as an
+/// overlay toggled with `Action::ToggleInbox`. Replies are composed through
+/// `Mode::Input` while a conversation is selected.
+pub struct Inbox {
+    ctx: Arc<Ctx>,
+    open: bool,
+    conversations: Vec<Conversation>,
+    selected: usize,
+    draft: Option<String>,
+}
+
+impl Inbox {
+    pub fn new(ctx: Arc<Ctx>) -> Self {
+        spawn_poll(Arc::clone(&ctx));
+
+        Self {
+            ctx,
+            open: false,
+            conversations: vec![],
+            selected: 0,
+            draft: None,
+        }
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.conversations.iter().filter(|c| !c.read).count()
+    }
+
+    fn send_draft(&mut self) -> Option<Action> {
+        let body = self.draft.take()?;
+        let recipient_id = self.conversations.get(self.selected)?.other_party_id;
+
+        let ctx = Arc::clone(&self.ctx);
+        let id = next_request_id();
+        let _ = ctx.action_tx.send(Action::RequestStarted {
+            id,
+            label: "send message".to_string(),
+        });
+
+        tokio::spawn(async move {
+            let status = send_message(&ctx, recipient_id, body).await;
+
+            let _ = ctx.action_tx.send(Action::RequestFinished { id, status });
+            let _ = ctx.action_tx.send(Action::Render);
+        });
+
+        Some(Action::SwitchToNormalMode)
+    }
+}
+
+fn spawn_poll(ctx: Arc<Ctx>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_secs(30));
+
+        loop {
+            ticker.tick().await;
+
+            let id = next_request_id();
+            let _ = ctx.action_tx.send(Action::RequestStarted {
+                id,
+                label: "fetch inbox".to_string(),
+            });
+
+            let status = match fetch_conversations(&ctx).await {
+                Ok(conversations) => {
+                    let _ = ctx.action_tx.send(Action::InboxUpdated(conversations));
+                    RequestStatus::Done
+                }
+                Err(err) => RequestStatus::Error(err.to_string()),
+            };
+
+            let _ = ctx.action_tx.send(Action::RequestFinished { id, status });
+            let _ = ctx.action_tx.send(Action::Render);
+        }
+    });
+}
+
+/// Posts a reply, returning the outcome as a `RequestStatus` for `StatusTracker`
+/// rather than silently swallowing the error.
+async fn send_message(ctx: &Ctx, recipient_id: i32, content: String) -> RequestStatus {
+    let Some(jwt) = ctx.jwt() else {
+        return RequestStatus::Error("not logged in".to_string());
+    };
+
+    let result = ctx
+        .client
+        .post(format!(
+            "https://{}/api/v3/private_message",
+            ctx.config.connection.instance
+        ))
+        .json(&CreatePrivateMessage {
+            content,
+            recipient_id,
+            auth: jwt.into(),
+        })
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status);
+
+    match result {
+        Ok(_) => RequestStatus::Done,
+        Err(err) => RequestStatus::Error(err.to_string()),
+    }
+}
+
+async fn fetch_conversations(ctx: &Ctx) -> Result<Vec<Conversation>> {
+    let Some(jwt) = ctx.jwt() else {
+        return Ok(vec![]);
+    };
+
+    let response: PrivateMessagesResponse = ctx
+        .client
+        .get(format!(
+            "https://{}/api/v3/private_message/list",
+            ctx.config.connection.instance
+        ))
+        .query(&GetPrivateMessages {
+            auth: jwt.into(),
+            ..Default::default()
+        })
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    Ok(response
+        .private_messages
+        .into_iter()
+        .map(conversation_from_view)
+        .collect())
+}
+
+fn conversation_from_view(view: PrivateMessageView) -> Conversation {
+    Conversation {
+        other_party_id: view.creator.id.0 as i32,
+        other_party_name: view.creator.name,
+        latest_message: view.private_message.content,
+        read: view.private_message.read,
+    }
+}
+
+impl Component for Inbox {
+    fn handle_actions(&mut self, action: Action) -> Option<Action> {
+        match action {
+            Action::ToggleInbox => {
+                self.open = !self.open;
+                Some(Action::Render)
+            }
+
+            Action::ScrollDown(count) if self.open && self.draft.is_none() => {
+                if !self.conversations.is_empty() {
+                    self.selected = (self.selected + count).min(self.conversations.len() - 1);
+                }
+                Some(Action::Render)
+            }
+
+            Action::ScrollUp(count) if self.open && self.draft.is_none() => {
+                self.selected = self.selected.saturating_sub(count);
+                Some(Action::Render)
+            }
+
+            Action::InboxComposeReply if self.open && !self.conversations.is_empty() => {
+                self.draft = Some(String::new());
+                Some(Action::SwitchToInputMode)
+            }
+
+            Action::InputChar(c) if self.draft.is_some() => {
+                self.draft.as_mut().unwrap().push(c);
+                Some(Action::Render)
+            }
+
+            Action::InputBackspace if self.draft.is_some() => {
+                self.draft.as_mut().unwrap().pop();
+                Some(Action::Render)
+            }
+
+            Action::InputSubmit if self.draft.is_some() => self.send_draft(),
+
+            // Cancel the reply instead of leaving a stale draft rendered and
+            // `j`/`k` stuck disabled (they're guarded on `draft.is_none()`).
+            Action::SwitchToNormalMode if self.draft.is_some() => {
+                self.draft = None;
+                Some(Action::Render)
+            }
+
+            Action::InboxUpdated(conversations) => {
+                self.conversations = conversations;
+                Some(Action::Render)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let layout = Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).split(rect);
+        let theme = self.ctx.theme.read().unwrap();
+
+        let items: Vec<ListItem> = self
+            .conversations
+            .iter()
+            .map(|conversation| {
+                let badge = if conversation.read {
+                    Span::raw("")
+                } else {
+                    Span::styled(" \u{25cf}", theme.unread_badge_style())
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!(
+                        "{}: {}",
+                        conversation.other_party_name, conversation.latest_message
+                    )),
+                    badge,
+                ]))
+            })
+            .collect();
+
+        let mut state = ListState::default();
+        if !self.conversations.is_empty() {
+            state.select(Some(self.selected));
+        }
+
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Inbox"))
+            .highlight_style(theme.selected_highlight_style());
+
+        f.render_stateful_widget(list, layout[0], &mut state);
+
+        if let Some(draft) = &self.draft {
+            let input = Paragraph::new(draft.clone())
+                .block(Block::default().borders(Borders::ALL).title("Reply"));
+            f.render_widget(input, layout[1]);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use ratatui::{prelude::*, widgets::Paragraph};
+
+use crate::{
+    action::Action,
+    app::Ctx,
+    language_model::{LanguageModel, TruncationDirection},
+    status::{next_request_id, RequestStatus},
+    ui::components::Component,
+};
+
+pub struct LemmynatorPost {
+    pub id: i32,
+    pub body: String,
+    pub is_focused: bool,
+    pub summary: Option<String>,
+    pub showing_summary: bool,
+    pub ctx: Arc<Ctx>,
+}
+
+impl LemmynatorPost {
+    pub fn is_image_only(&self) -> bool {
+        false
+    }
+
+    pub fn image_is_wide(&self) -> Option<bool> {
+        None
+    }
+
+    /// Body currently shown to the user: the AI summary if one has been fetched
+    /// and isn't toggled off, otherwise the raw post body.
+    pub fn displayed_body(&self) -> &str {
+        if self.showing_summary {
+            self.summary.as_deref().unwrap_or(&self.body)
+        } else {
+            &self.body
+        }
+    }
+
+    pub fn toggle_summary(&mut self) {
+        self.showing_summary = !self.showing_summary;
+    }
+
+    /// Summarizes `body` in the background if its `language_model` token count
+    /// exceeds the configured `summarization.token_threshold`, truncating to the model's capacity (keeping the End) so the
+    /// request never exceeds it. Tracked through `StatusTracker` like any other
+    /// async fetch, so a slow or failing completion endpoint shows a toast
+    /// instead of leaving the post silently stuck. Sends `Action::PostSummarized`
+    /// (or `Action::PostSummaryFailed`) followed by `Action::Render` when done.
+    pub fn summarize(&self, ctx: Arc<Ctx>, language_model: Arc<dyn LanguageModel>) {
+        let post_id = self.id;
+        let body = self.body.clone();
+        let threshold = ctx.config.summarization.token_threshold;
+
+        if language_model.count_tokens(&body) <= threshold {
+            return;
+        }
+
+        let id = next_request_id();
+        let _ = ctx.action_tx.send(Action::RequestStarted {
+            id,
+            label: format!("summarize post {post_id}"),
+        });
+
+        tokio::spawn(async move {
+            let truncated =
+                language_model.truncate(&body, language_model.capacity(), TruncationDirection::End);
+
+            let (status, result_action) = match language_model.summarize(&truncated).await {
+                Ok(summary) => (
+                    RequestStatus::Done,
+                    Action::PostSummarized { post_id, summary },
+                ),
+                Err(err) => (
+                    RequestStatus::Error(err.to_string()),
+                    Action::PostSummaryFailed {
+                        post_id,
+                        error: err.to_string(),
+                    },
+                ),
+            };
+
+            let _ = ctx.action_tx.send(Action::RequestFinished { id, status });
+            let _ = ctx.action_tx.send(result_action);
+            let _ = ctx.action_tx.send(Action::Render);
+        });
+    }
+
+    /// Number of terminal rows this post needs to render, varying with whether it
+    /// has a body and the aspect ratio of its image (if any).
+    pub fn height(&self) -> u16 {
+        if self.body.is_empty() && !self.is_image_only() {
+            5
+        } else if let Some(image_is_wide) = self.image_is_wide() {
+            if image_is_wide {
+                7
+            } else {
+                8
+            }
+        } else {
+            7
+        }
+    }
+}
+
+impl Component for LemmynatorPost {
+    fn handle_actions(&mut self, _action: Action) -> Option<Action> {
+        None
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        let style = if self.is_focused {
+            self.ctx.theme.read().unwrap().focused_border_style()
+        } else {
+            Style::default()
+        };
+
+        f.render_widget(
+            Paragraph::new(self.displayed_body().to_string()).style(style),
+            rect,
+        );
+    }
+}
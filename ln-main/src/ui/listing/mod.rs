@@ -0,0 +1,2 @@
+pub mod lemmynator_post;
+pub mod page;
@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use lemmy_api_common::lemmy_db_views::structs::PaginationCursor;
 use ratatui::{prelude::*, widgets::Paragraph};
 
-use crate::{action::Action, ui::components::Component};
+use crate::{action::Action, app::Ctx, language_model::LanguageModel, ui::components::Component};
 
 use super::lemmynator_post::LemmynatorPost;
 
@@ -11,38 +13,112 @@ pub struct Page {
     pub posts_offset: usize,
     pub currently_focused: u8,
     pub currently_displaying: u8,
+    ctx: Arc<Ctx>,
+    language_model: Option<Arc<dyn LanguageModel>>,
 }
 
 impl Page {
-    pub fn new() -> Self {
+    pub fn new(ctx: Arc<Ctx>, language_model: Option<Arc<dyn LanguageModel>>) -> Self {
         Page {
             posts: vec![],
             next_page: None,
             posts_offset: 0,
             currently_focused: 0,
             currently_displaying: 0,
+            ctx,
+            language_model,
         }
     }
 
-    fn scroll_up(&mut self) {
-        if self.currently_focused == 0 && self.posts_offset != 0 {
-            self.posts_offset -= self.currently_displaying as usize;
-            self.currently_focused = self.currently_displaying - 1;
-        } else if self.currently_focused != 0 {
-            self.currently_focused -= 1;
-        }
+    fn currently_focused_absolute(&self) -> usize {
+        self.posts_offset + self.currently_focused as usize
     }
 
-    fn scroll_down(&mut self) {
-        self.currently_focused += 1;
-        if self.currently_focused >= self.currently_displaying {
-            self.posts_offset += self.currently_displaying as usize;
+    /// Moves the focus to `target` (an absolute post index), clamping to the last
+    /// loaded post. If `target` falls past the loaded tail and a `next_page` is
+    /// still available, fires `Action::FetchNextPage` through `action_tx` so the
+    /// caller can trigger it -- sent directly rather than returned, so a motion
+    /// that needs a fetch doesn't also swallow the redraw every scroll needs.
+    /// `target` becomes the first visible post whenever it falls outside the
+    /// currently packed window, since that window's size varies post to post.
+    fn scroll_to(&mut self, target: usize) {
+        if self.posts.is_empty() {
+            return;
+        }
+
+        let last_loaded = self.posts.len() - 1;
+        let target = if target > last_loaded {
+            if self.next_page.is_some() {
+                let _ = self.ctx.action_tx.send(Action::FetchNextPage);
+            }
+            last_loaded
+        } else {
+            target
+        };
+
+        let visible_end = self.posts_offset + self.currently_displaying as usize;
+        if self.currently_displaying == 0 || target < self.posts_offset || target >= visible_end {
+            self.posts_offset = target;
             self.currently_focused = 0;
+        } else {
+            self.currently_focused = (target - self.posts_offset) as u8;
         }
     }
 
-    fn update_count_of_currently_displaying(&mut self, rect: Rect) {
-        self.currently_displaying = (rect.height / 8) as u8;
+    fn scroll_up(&mut self, count: usize) {
+        self.scroll_to(self.currently_focused_absolute().saturating_sub(count));
+    }
+
+    fn scroll_down(&mut self, count: usize) {
+        self.scroll_to(self.currently_focused_absolute() + count);
+    }
+
+    fn jump_to_first(&mut self) {
+        self.scroll_to(0);
+    }
+
+    fn jump_to_last(&mut self) {
+        self.scroll_to(usize::MAX);
+    }
+
+    fn page_up(&mut self) {
+        self.scroll_up(self.currently_displaying as usize);
+    }
+
+    fn page_down(&mut self) {
+        self.scroll_down(self.currently_displaying as usize);
+    }
+
+    fn half_page_up(&mut self) {
+        self.scroll_up(self.currently_displaying as usize / 2);
+    }
+
+    fn half_page_down(&mut self) {
+        self.scroll_down(self.currently_displaying as usize / 2);
+    }
+
+    /// Greedily packs as many posts starting at `posts_offset` as fit in `rect`,
+    /// using each post's own (non-uniform) `height()`. Always shows at least one
+    /// post, even an oversized one that won't fully fit, rather than showing none.
+    fn pack_visible_posts(&mut self, rect: Rect) {
+        if self.posts.is_empty() {
+            self.currently_displaying = 0;
+            return;
+        }
+
+        let mut remaining_height = rect.height;
+        let mut count: u8 = 0;
+
+        for post in &self.posts[self.posts_offset..] {
+            let post_height = post.height();
+            if count > 0 && post_height > remaining_height {
+                break;
+            }
+            remaining_height = remaining_height.saturating_sub(post_height);
+            count += 1;
+        }
+
+        self.currently_displaying = count;
     }
 
     fn rects_for_posts(&mut self, mut rect_pool: Rect) -> Vec<Rect> {
@@ -51,19 +127,7 @@ impl Page {
 
         let mut rects = vec![];
         for post in posts {
-            let vertical_length = {
-                if post.body.is_empty() && !post.is_image_only() {
-                    5
-                } else if let Some(image_is_wide) = post.image_is_wide() {
-                    if image_is_wide {
-                        7
-                    } else {
-                        8
-                    }
-                } else {
-                    7
-                }
-            };
+            let vertical_length = post.height().min(rect_pool.height);
             let layout = Layout::vertical(vec![
                 Constraint::Length(vertical_length),
                 Constraint::Percentage(100),
@@ -107,12 +171,16 @@ impl Page {
 
     pub fn render_bottom_bar(&mut self, f: &mut Frame, rect: Rect) {
         if self.currently_displaying != 0 {
-            let current_page_paragraph = Paragraph::new(format!(
-                "{} / ",
-                (self.posts_offset / self.currently_displaying as usize) + 1,
+            let first_visible = self.posts_offset + 1;
+            let last_visible = self.posts_offset + self.currently_displaying as usize;
+
+            let bottom_bar_paragraph = Paragraph::new(format!(
+                "{first_visible}-{last_visible} of {}",
+                self.posts.len()
             ))
+            .style(self.ctx.theme.read().unwrap().bottom_bar_style())
             .alignment(Alignment::Center);
-            f.render_widget(current_page_paragraph, rect);
+            f.render_widget(bottom_bar_paragraph, rect);
         }
     }
 }
@@ -120,27 +188,74 @@ impl Page {
 impl Component for Page {
     fn handle_actions(&mut self, action: Action) -> Option<Action> {
         match action {
-            Action::Up => {
-                self.scroll_up();
+            Action::ScrollUp(_)
+            | Action::ScrollDown(_)
+            | Action::JumpToFirst
+            | Action::JumpToLast
+            | Action::PageUp
+            | Action::PageDown
+            | Action::HalfPageUp
+            | Action::HalfPageDown => {
+                match action {
+                    Action::ScrollUp(count) => self.scroll_up(count),
+                    Action::ScrollDown(count) => self.scroll_down(count),
+                    Action::JumpToFirst => self.jump_to_first(),
+                    Action::JumpToLast => self.jump_to_last(),
+                    Action::PageUp => self.page_up(),
+                    Action::PageDown => self.page_down(),
+                    Action::HalfPageUp => self.half_page_up(),
+                    Action::HalfPageDown => self.half_page_down(),
+                    _ => unreachable!(),
+                };
+
                 Some(Action::Render)
             }
-            Action::Down => {
-                self.scroll_down();
+
+            Action::SummarizeFocused => {
+                let index = self.currently_focused_absolute();
+
+                if matches!(self.posts.get(index), Some(post) if post.summary.is_some()) {
+                    self.posts[index].toggle_summary();
+                    return Some(Action::Render);
+                }
+
+                if let Some(language_model) = &self.language_model {
+                    if let Some(post) = self.posts.get(index) {
+                        post.summarize(Arc::clone(&self.ctx), Arc::clone(language_model));
+                    }
+                }
+
+                None
+            }
+
+            Action::PostSummarized { post_id, summary } => {
+                if let Some(post) = self.posts.iter_mut().find(|post| post.id == post_id) {
+                    post.summary = Some(summary);
+                    post.showing_summary = true;
+                }
                 Some(Action::Render)
             }
+
+            Action::PostSummaryFailed { .. } => Some(Action::Render),
+
+            // Fetching `next_page` and appending to `self.posts` isn't implemented
+            // in this tree yet; `scroll_to` still fires the action so a future
+            // fetch handler has something to listen for.
+            Action::FetchNextPage => None,
+
             _ => None,
         }
     }
 
     fn render(&mut self, f: &mut Frame, rect: Rect) {
-        self.update_count_of_currently_displaying(rect);
+        self.pack_visible_posts(rect);
 
         let main_rect = rect;
 
-        let current_page = self.posts_offset / self.currently_displaying as usize;
-        if current_page > 3 {
-            self.posts.drain(0..2 * self.currently_displaying as usize);
-            self.posts_offset -= self.currently_displaying as usize * 2;
+        if self.currently_displaying != 0 && self.posts_offset > 3 * self.currently_displaying as usize {
+            let to_drain = 2 * self.currently_displaying as usize;
+            self.posts.drain(0..to_drain);
+            self.posts_offset -= to_drain;
         }
 
         let mut rects = self.rects_for_posts(rect);
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use ratatui::prelude::*;
+
+use crate::{
+    action::Action,
+    app::Ctx,
+    language_model::HttpLanguageModel,
+    ui::{
+        command_palette::CommandPalette, components::Component, inbox::Inbox,
+        listing::page::Page,
+    },
+};
+
+pub struct MainWindow {
+    ctx: Arc<Ctx>,
+    page: Page,
+    command_palette: Option<CommandPalette>,
+    command_recency: Vec<usize>,
+    inbox: Inbox,
+}
+
+impl MainWindow {
+    pub async fn new(ctx: Arc<Ctx>) -> Result<Self> {
+        let language_model = ctx
+            .config
+            .summarization
+            .enabled
+            .then(|| {
+                HttpLanguageModel::new(
+                    ctx.config.summarization.model_name.clone(),
+                    ctx.config.summarization.base_url.clone(),
+                    ctx.config.summarization.api_key.clone(),
+                    ctx.config.summarization.capacity,
+                )
+            })
+            .transpose()?
+            .map(|model| Arc::new(model) as Arc<dyn crate::language_model::LanguageModel>);
+
+        Ok(Self {
+            page: Page::new(Arc::clone(&ctx), language_model),
+            inbox: Inbox::new(Arc::clone(&ctx)),
+            ctx,
+            command_palette: None,
+            command_recency: vec![],
+        })
+    }
+
+    fn close_command_palette(&mut self) {
+        if let Some(command_palette) = self.command_palette.take() {
+            self.command_recency = command_palette.into_recency();
+        }
+    }
+}
+
+impl Component for MainWindow {
+    fn handle_actions(&mut self, action: Action) -> Option<Action> {
+        match action {
+            Action::SwitchToCommandMode => {
+                self.command_palette = Some(CommandPalette::new(
+                    Arc::clone(&self.ctx),
+                    self.command_recency.clone(),
+                ));
+                Some(Action::Render)
+            }
+
+            Action::SwitchToNormalMode if self.command_palette.is_some() => {
+                self.close_command_palette();
+                Some(Action::Render)
+            }
+
+            // Close the palette before dispatching the chosen action: while it's
+            // still open every action gets routed into its `handle_actions` below
+            // and dropped, since the palette only reacts to `CommandPalette*`.
+            Action::CommandPaletteSubmit if self.command_palette.is_some() => {
+                let chosen = self
+                    .command_palette
+                    .as_mut()
+                    .and_then(CommandPalette::execute_selected);
+                self.close_command_palette();
+                chosen.or(Some(Action::Render))
+            }
+
+            _ => {
+                if let Some(command_palette) = &mut self.command_palette {
+                    return command_palette.handle_actions(action);
+                }
+
+                if let Some(result) = self.inbox.handle_actions(action.clone()) {
+                    return Some(result);
+                }
+
+                self.page.handle_actions(action)
+            }
+        }
+    }
+
+    fn render(&mut self, f: &mut Frame, rect: Rect) {
+        self.page.render(f, rect);
+
+        let unread = self.inbox.unread_count();
+        if unread > 0 {
+            let badge_rect = Rect {
+                x: rect.right().saturating_sub(12).max(rect.x),
+                y: rect.y,
+                width: 12.min(rect.width),
+                height: 1.min(rect.height),
+            };
+            f.render_widget(
+                ratatui::widgets::Paragraph::new(format!("\u{2709} {unread}"))
+                    .style(self.ctx.theme.read().unwrap().unread_badge_style())
+                    .alignment(Alignment::Right),
+                badge_rect,
+            );
+        }
+
+        self.inbox.render(f, rect);
+
+        if let Some(command_palette) = &mut self.command_palette {
+            command_palette.render(f, rect);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod command_palette;
+pub mod components;
+pub mod inbox;
+pub mod listing;
+pub mod main_ui;